@@ -7,7 +7,8 @@ use ink::{
 
 #[cfg_attr(test, allow(dead_code))]
 
-const _ON_ERC_1155_BATCH_RECEIVED_SELECTOR: [u8; 4] = [0xBC, 0x19, 0x7C, 0x81];
+const ON_ERC_1155_RECEIVED_SELECTOR: [u8; 4] = [0xF2, 0x3A, 0x6E, 0x61];
+const ON_ERC_1155_BATCH_RECEIVED_SELECTOR: [u8; 4] = [0xBC, 0x19, 0x7C, 0x81];
 pub type TokenId = u128;
 type Balance = <ink::env::DefaultEnvironment as ink::env::Environment>::Balance;
 
@@ -32,11 +33,30 @@ pub enum Error {
     TokenAlreadyExists,
     /// The token ID does not exist or the caller is not the owner of the token.
     UnexistentTokenOrCallerNotOwner,
+    /// The recipient is a contract which does not accept the token transfer.
+    TransferRejected,
+    /// The voucher's signature does not recover to the configured minter authority.
+    InvalidVoucherSignature,
+    /// The voucher's nonce has already been redeemed.
+    VoucherAlreadyUsed,
 }
 
 // The ERC-1155 result types.
 pub type Result<T> = core::result::Result<T, Error>;
 
+/// A lazily-minted edition, signed off-chain by the contract's `minter` authority.
+///
+/// The recipient redeems it via `mint_with_voucher`, paying the gas themselves instead
+/// of the authority minting directly.
+#[derive(Debug, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub struct Voucher {
+    pub token_id: TokenId,
+    pub value: Balance,
+    pub recipient: AccountId,
+    pub nonce: u128,
+}
+
 /// Evaluate `$x:expr` and if not true return `Err($y:expr)`.
 ///
 /// Used as `ensure!(expression_to_ensure, expression_to_return_on_false)`.
@@ -128,6 +148,21 @@ mod songnft {
         value: Balance,
     }
 
+    /// Indicate that a batch token transfer has occured.
+    ///
+    /// This must be emitted even if a zero value transfer occurs.
+    #[ink(event)]
+    pub struct TransferBatch {
+        #[ink(topic)]
+        operator: Option<AccountId>,
+        #[ink(topic)]
+        from: Option<AccountId>,
+        #[ink(topic)]
+        to: Option<AccountId>,
+        token_ids: Vec<TokenId>,
+        values: Vec<Balance>,
+    }
+
     /// Indicate that an approval event has happened.
     #[ink(event)]
     pub struct ApprovalForAll {
@@ -151,6 +186,13 @@ mod songnft {
     pub struct Contract {
         balances: Mapping<(AccountId, TokenId), Balance>,
         approvals: Mapping<(Owner, Operator), ()>,
+        total_supply: Mapping<TokenId, Balance>,
+        total_supply_all: Balance,
+        token_creators: Mapping<TokenId, AccountId>,
+        token_uris: Mapping<TokenId, ink::prelude::string::String>,
+        base_uri: ink::prelude::string::String,
+        minter: [u8; 33],
+        used_nonces: Mapping<u128, ()>,
     }
 
     impl Contract {
@@ -159,11 +201,32 @@ mod songnft {
             Default::default()
         }
 
+        /// Instantiate the contract with a base URI, e.g. `https://example.com/{id}.json`,
+        /// used by [`Contract::uri`] for tokens that have no per-token URI set.
+        #[ink(constructor)]
+        pub fn new_with_base_uri(base_uri: ink::prelude::string::String) -> Self {
+            Self {
+                base_uri,
+                ..Default::default()
+            }
+        }
+
+        /// Instantiate the contract with a `minter` authority, the compressed ECDSA
+        /// public key that signs [`Voucher`]s for [`Contract::mint_with_voucher`].
+        #[ink(constructor)]
+        pub fn new_with_minter(minter: [u8; 33]) -> Self {
+            Self {
+                minter,
+                ..Default::default()
+            }
+        }
+
         #[ink(message)]
         pub fn create(&mut self, value: Balance, token_id: u128) -> Result<TokenId> {
             let caller = self.env().caller();
-            ensure!(!self.balances.contains((caller, token_id)), Error::TokenAlreadyExists);
-            self.balances.insert((caller, token_id), &value);
+            ensure!(!self.token_creators.contains(token_id), Error::TokenAlreadyExists);
+            self.token_creators.insert(token_id, &caller);
+            self._update(None, Some(caller), token_id, value)?;
 
             self.env().emit_event(TransferSingle {
                 operator: Some(caller),
@@ -178,8 +241,11 @@ mod songnft {
         #[ink(message)]
         pub fn mint(&mut self, token_id: TokenId, value: Balance) -> Result<()> {
             let caller = self.env().caller();
-            ensure!(self.balances.contains((caller, token_id)), Error::UnexistentTokenOrCallerNotOwner);
-            self.balances.insert((caller, token_id), &value);
+            ensure!(
+                self.token_creators.get(token_id) == Some(caller),
+                Error::UnexistentTokenOrCallerNotOwner
+            );
+            self._update(None, Some(caller), token_id, value)?;
 
             self.env().emit_event(TransferSingle {
                 operator: Some(caller),
@@ -191,6 +257,376 @@ mod songnft {
 
             Ok(())
         }
+
+        /// Redeem a [`Voucher`] signed by the `minter` authority, minting its edition
+        /// to `voucher.recipient` and paying the gas as the caller.
+        ///
+        /// The signature must be a 65-byte ECDSA signature over the Keccak-256 hash of
+        /// the SCALE-encoded voucher. Each `voucher.nonce` can only be redeemed once.
+        #[ink(message)]
+        pub fn mint_with_voucher(&mut self, voucher: Voucher, signature: [u8; 65]) -> Result<()> {
+            ensure!(
+                !self.used_nonces.contains(voucher.nonce),
+                Error::VoucherAlreadyUsed
+            );
+
+            let mut message_hash = [0u8; 32];
+            ink::env::hash_bytes::<ink::env::hash::Keccak256>(
+                &ink::scale::Encode::encode(&voucher),
+                &mut message_hash,
+            );
+
+            let mut signer = [0u8; 33];
+            ink::env::ecdsa_recover(&signature, &message_hash, &mut signer)
+                .map_err(|_| Error::InvalidVoucherSignature)?;
+            ensure!(signer == self.minter, Error::InvalidVoucherSignature);
+
+            self.used_nonces.insert(voucher.nonce, &());
+
+            let caller = self.env().caller();
+            self._update(None, Some(voucher.recipient), voucher.token_id, voucher.value)?;
+
+            self.env().emit_event(TransferSingle {
+                operator: Some(caller),
+                from: None,
+                to: Some(voucher.recipient),
+                token_id: voucher.token_id,
+                value: voucher.value,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn total_supply(&self, token_id: TokenId) -> Balance {
+            self.total_supply.get(token_id).unwrap_or(0)
+        }
+
+        #[ink(message)]
+        pub fn total_supply_all(&self) -> Balance {
+            self.total_supply_all
+        }
+
+        #[ink(message)]
+        pub fn exists(&self, token_id: TokenId) -> bool {
+            self.total_supply(token_id) > 0
+        }
+
+        /// Move `value` of `token_id` from `from` to `to`, tracking supply.
+        ///
+        /// Passing `None` for `from` mints (adding to supply); passing `None` for `to`
+        /// burns (subtracting from supply). Passing `Some` for both is a plain transfer
+        /// and leaves supply unchanged.
+        fn _update(
+            &mut self,
+            from: Option<AccountId>,
+            to: Option<AccountId>,
+            token_id: TokenId,
+            value: Balance,
+        ) -> Result<()> {
+            if let Some(from) = from {
+                let balance_from = self.balance_of(from, token_id);
+                ensure!(balance_from >= value, Error::InsufficientBalance);
+                self.balances.insert((from, token_id), &(balance_from - value));
+            } else {
+                self.total_supply.insert(token_id, &(self.total_supply(token_id) + value));
+                self.total_supply_all += value;
+            }
+
+            if let Some(to) = to {
+                let balance_to = self.balance_of(to, token_id);
+                self.balances.insert((to, token_id), &(balance_to + value));
+            } else {
+                self.total_supply.insert(token_id, &(self.total_supply(token_id) - value));
+                self.total_supply_all -= value;
+            }
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set_approval_for_all(&mut self, operator: AccountId, approved: bool) -> Result<()> {
+            let caller = self.env().caller();
+            ensure!(operator != caller, Error::SelfApproval);
+
+            if approved {
+                self.approvals.insert((caller, operator), &());
+            } else {
+                self.approvals.remove((caller, operator));
+            }
+
+            self.env().emit_event(ApprovalForAll {
+                owner: caller,
+                operator,
+                approved,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn is_approved_for_all(&self, owner: AccountId, operator: AccountId) -> bool {
+            self.approvals.contains((owner, operator))
+        }
+
+        #[ink(message)]
+        pub fn safe_transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            token_id: TokenId,
+            value: Balance,
+            data: Vec<u8>,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            ensure!(
+                caller == from || self.is_approved_for_all(from, caller),
+                Error::NotApproved
+            );
+            ensure!(to != zero_address(), Error::ZeroAddressTransfer);
+
+            self.transfer_token_from(&from, &to, token_id, value)?;
+
+            self.env().emit_event(TransferSingle {
+                operator: Some(caller),
+                from: Some(from),
+                to: Some(to),
+                token_id,
+                value,
+            });
+
+            self.transfer_acceptance_check(caller, from, to, token_id, value, data)?;
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn safe_batch_transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            token_ids: Vec<TokenId>,
+            values: Vec<Balance>,
+            data: Vec<u8>,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            ensure!(
+                caller == from || self.is_approved_for_all(from, caller),
+                Error::NotApproved
+            );
+            ensure!(to != zero_address(), Error::ZeroAddressTransfer);
+            ensure!(token_ids.len() == values.len(), Error::BatchTransferMismatch);
+
+            for (&token_id, &value) in token_ids.iter().zip(values.iter()) {
+                self.transfer_token_from(&from, &to, token_id, value)?;
+            }
+
+            self.env().emit_event(TransferBatch {
+                operator: Some(caller),
+                from: Some(from),
+                to: Some(to),
+                token_ids: token_ids.clone(),
+                values: values.clone(),
+            });
+
+            self.batch_transfer_acceptance_check(caller, from, to, token_ids, values, data)?;
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn burn(&mut self, from: AccountId, token_id: TokenId, value: Balance) -> Result<()> {
+            let caller = self.env().caller();
+            ensure!(
+                caller == from || self.is_approved_for_all(from, caller),
+                Error::NotApproved
+            );
+
+            self._update(Some(from), None, token_id, value)?;
+
+            self.env().emit_event(TransferSingle {
+                operator: Some(caller),
+                from: Some(from),
+                to: None,
+                token_id,
+                value,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn burn_batch(
+            &mut self,
+            from: AccountId,
+            token_ids: Vec<TokenId>,
+            values: Vec<Balance>,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            ensure!(
+                caller == from || self.is_approved_for_all(from, caller),
+                Error::NotApproved
+            );
+            ensure!(token_ids.len() == values.len(), Error::BatchTransferMismatch);
+
+            for (&token_id, &value) in token_ids.iter().zip(values.iter()) {
+                self._update(Some(from), None, token_id, value)?;
+            }
+
+            self.env().emit_event(TransferBatch {
+                operator: Some(caller),
+                from: Some(from),
+                to: None,
+                token_ids,
+                values,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set_uri(
+            &mut self,
+            token_id: TokenId,
+            uri: ink::prelude::string::String,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            ensure!(
+                self.token_creators.get(token_id) == Some(caller),
+                Error::UnexistentTokenOrCallerNotOwner
+            );
+
+            self.token_uris.insert(token_id, &uri);
+
+            self.env().emit_event(Uri {
+                value: uri,
+                token_id,
+            });
+
+            Ok(())
+        }
+
+        /// Resolve the metadata URI for `token_id`.
+        ///
+        /// Returns the per-token URI if one was set with [`Contract::set_uri`].
+        /// Otherwise, if a base URI was set at construction, returns it with any
+        /// `{id}` placeholder replaced by the lowercase, zero-padded 32-byte hex
+        /// encoding of `token_id`, per the ERC-1155 metadata convention. Returns
+        /// `None` if neither is set.
+        #[ink(message)]
+        pub fn uri(&self, token_id: TokenId) -> Option<ink::prelude::string::String> {
+            if let Some(uri) = self.token_uris.get(token_id) {
+                return Some(uri);
+            }
+
+            if self.base_uri.is_empty() {
+                return None;
+            }
+
+            let hex_id = ink::prelude::format!("{:064x}", token_id);
+            Some(self.base_uri.replace("{id}", &hex_id))
+        }
+
+        /// Debit `from` and credit `to` with `value` of `token_id`, without emitting
+        /// events or performing any receiver checks.
+        fn transfer_token_from(
+            &mut self,
+            from: &AccountId,
+            to: &AccountId,
+            token_id: TokenId,
+            value: Balance,
+        ) -> Result<()> {
+            self._update(Some(*from), Some(*to), token_id, value)
+        }
+
+        /// If `to` is a contract, invoke its `on_received` hook and revert with
+        /// `Error::TransferRejected` unless it returns `ON_ERC_1155_RECEIVED_SELECTOR`.
+        fn transfer_acceptance_check(
+            &mut self,
+            _caller: AccountId,
+            _from: AccountId,
+            _to: AccountId,
+            _token_id: TokenId,
+            _value: Balance,
+            _data: Vec<u8>,
+        ) -> Result<()> {
+            // Disabled during tests since the off-chain environment does not support
+            // `invoke_contract()`; see the ink! ERC-1155 example for the same caveat.
+            #[cfg(not(test))]
+            {
+                // A plain account (EOA) has no `on_received` to call into; only a
+                // contract recipient must accept the transfer.
+                if !self.env().is_contract(&_to) {
+                    return Ok(());
+                }
+
+                use ink::env::call::{build_call, ExecutionInput, Selector};
+
+                let result = build_call::<Environment>()
+                    .call(_to)
+                    .exec_input(
+                        ExecutionInput::new(Selector::new(ON_ERC_1155_RECEIVED_SELECTOR))
+                            .push_arg(_caller)
+                            .push_arg(_from)
+                            .push_arg(_token_id)
+                            .push_arg(_value)
+                            .push_arg(_data),
+                    )
+                    .returns::<Vec<u8>>()
+                    .try_invoke();
+
+                match result {
+                    Ok(Ok(selector)) if selector.as_slice() == ON_ERC_1155_RECEIVED_SELECTOR.as_slice() => {}
+                    _ => return Err(Error::TransferRejected),
+                }
+            }
+
+            Ok(())
+        }
+
+        /// If `to` is a contract, invoke its `on_batch_received` hook and revert with
+        /// `Error::TransferRejected` unless it returns `ON_ERC_1155_BATCH_RECEIVED_SELECTOR`.
+        fn batch_transfer_acceptance_check(
+            &mut self,
+            _caller: AccountId,
+            _from: AccountId,
+            _to: AccountId,
+            _token_ids: Vec<TokenId>,
+            _values: Vec<Balance>,
+            _data: Vec<u8>,
+        ) -> Result<()> {
+            #[cfg(not(test))]
+            {
+                // A plain account (EOA) has no `on_batch_received` to call into; only a
+                // contract recipient must accept the transfer.
+                if !self.env().is_contract(&_to) {
+                    return Ok(());
+                }
+
+                use ink::env::call::{build_call, ExecutionInput, Selector};
+
+                let result = build_call::<Environment>()
+                    .call(_to)
+                    .exec_input(
+                        ExecutionInput::new(Selector::new(ON_ERC_1155_BATCH_RECEIVED_SELECTOR))
+                            .push_arg(_caller)
+                            .push_arg(_from)
+                            .push_arg(_token_ids)
+                            .push_arg(_values)
+                            .push_arg(_data),
+                    )
+                    .returns::<Vec<u8>>()
+                    .try_invoke();
+
+                match result {
+                    Ok(Ok(selector))
+                        if selector.as_slice() == ON_ERC_1155_BATCH_RECEIVED_SELECTOR.as_slice() => {}
+                    _ => return Err(Error::TransferRejected),
+                }
+            }
+
+            Ok(())
+        }
     }
 
     impl super::Songnft for Contract {
@@ -250,6 +686,7 @@ mod songnft {
         /// Imports all the definitions from the outer scope so we can use them here.
         use super::*;
         use crate::Songnft;
+        use ink::prelude::string::String;
 
         fn set_sender(sender: AccountId) {
             ink::env::test::set_caller::<Environment>(sender);
@@ -328,5 +765,262 @@ mod songnft {
             let res = erc.mint(1, 123);
             assert_eq!(res.unwrap_err(), Error::UnexistentTokenOrCallerNotOwner);
         }
+
+        #[ink::test]
+        fn can_send_tokens_between_accounts() {
+            let mut erc = init_contract();
+
+            set_sender(alice());
+            assert!(erc.safe_transfer_from(alice(), bob(), 1, 5, vec![]).is_ok());
+            assert_eq!(erc.balance_of(alice(), 1), 5);
+            assert_eq!(erc.balance_of(bob(), 1), 15);
+        }
+
+        #[ink::test]
+        fn sending_too_many_tokens_fails() {
+            let mut erc = init_contract();
+
+            set_sender(alice());
+            let res = erc.safe_transfer_from(alice(), bob(), 1, 100, vec![]);
+            assert_eq!(res.unwrap_err(), Error::InsufficientBalance);
+        }
+
+        #[ink::test]
+        fn sending_tokens_to_zero_address_fails() {
+            let mut erc = init_contract();
+
+            set_sender(alice());
+            let res = erc.safe_transfer_from(alice(), zero_address(), 1, 5, vec![]);
+            assert_eq!(res.unwrap_err(), Error::ZeroAddressTransfer);
+        }
+
+        #[ink::test]
+        fn can_send_batch_tokens() {
+            let mut erc = init_contract();
+
+            set_sender(alice());
+            assert!(erc
+                .safe_batch_transfer_from(alice(), bob(), vec![1, 2], vec![5, 10], vec![])
+                .is_ok());
+            assert_eq!(erc.balance_of_batch(vec![alice(), bob()], vec![1, 2]), vec![5, 10, 15, 10]);
+        }
+
+        #[ink::test]
+        fn batch_transfer_mismatched_lengths_fails() {
+            let mut erc = init_contract();
+
+            set_sender(alice());
+            let res = erc.safe_batch_transfer_from(alice(), bob(), vec![1, 2], vec![5], vec![]);
+            assert_eq!(res.unwrap_err(), Error::BatchTransferMismatch);
+        }
+
+        #[ink::test]
+        fn transfers_require_ownership_or_approval() {
+            let mut erc = init_contract();
+
+            set_sender(bob());
+            let res = erc.safe_transfer_from(alice(), bob(), 1, 5, vec![]);
+            assert_eq!(res.unwrap_err(), Error::NotApproved);
+        }
+
+        #[ink::test]
+        fn approved_operator_can_transfer() {
+            let mut erc = init_contract();
+
+            set_sender(alice());
+            assert!(erc.set_approval_for_all(bob(), true).is_ok());
+            assert!(erc.is_approved_for_all(alice(), bob()));
+
+            set_sender(bob());
+            assert!(erc.safe_transfer_from(alice(), bob(), 1, 5, vec![]).is_ok());
+        }
+
+        #[ink::test]
+        fn self_approval_is_rejected() {
+            let mut erc = Contract::new();
+
+            set_sender(alice());
+            let res = erc.set_approval_for_all(alice(), true);
+            assert_eq!(res.unwrap_err(), Error::SelfApproval);
+        }
+
+        #[ink::test]
+        fn revoking_approval_works() {
+            let mut erc = init_contract();
+
+            set_sender(alice());
+            assert!(erc.set_approval_for_all(bob(), true).is_ok());
+            assert!(erc.set_approval_for_all(bob(), false).is_ok());
+            assert!(!erc.is_approved_for_all(alice(), bob()));
+        }
+
+        #[ink::test]
+        fn creating_and_minting_tracks_supply() {
+            let mut erc = Contract::new();
+
+            set_sender(alice());
+            assert!(!erc.exists(1));
+            assert_eq!(erc.create(10, 1u128).unwrap(), 1u128);
+            assert!(erc.exists(1));
+            assert_eq!(erc.total_supply(1), 10);
+            assert_eq!(erc.total_supply_all(), 10);
+
+            assert!(erc.mint(1u128, 5).is_ok());
+            assert_eq!(erc.balance_of(alice(), 1u128), 15);
+            assert_eq!(erc.total_supply(1), 15);
+            assert_eq!(erc.total_supply_all(), 15);
+        }
+
+        #[ink::test]
+        fn creating_same_token_twice_fails() {
+            let mut erc = Contract::new();
+
+            set_sender(alice());
+            assert!(erc.create(10, 1u128).is_ok());
+            let res = erc.create(5, 1u128);
+            assert_eq!(res.unwrap_err(), Error::TokenAlreadyExists);
+        }
+
+        #[ink::test]
+        fn burning_tokens_works() {
+            let mut erc = Contract::new();
+
+            set_sender(alice());
+            assert!(erc.create(10, 1u128).is_ok());
+            assert!(erc.burn(alice(), 1, 4).is_ok());
+            assert_eq!(erc.balance_of(alice(), 1), 6);
+            assert_eq!(erc.total_supply(1), 6);
+        }
+
+        #[ink::test]
+        fn burning_too_many_tokens_fails() {
+            let mut erc = Contract::new();
+
+            set_sender(alice());
+            assert!(erc.create(10, 1u128).is_ok());
+            let res = erc.burn(alice(), 1, 11);
+            assert_eq!(res.unwrap_err(), Error::InsufficientBalance);
+        }
+
+        #[ink::test]
+        fn burning_requires_ownership_or_approval() {
+            let mut erc = Contract::new();
+
+            set_sender(alice());
+            assert!(erc.create(10, 1u128).is_ok());
+
+            set_sender(bob());
+            let res = erc.burn(alice(), 1, 4);
+            assert_eq!(res.unwrap_err(), Error::NotApproved);
+        }
+
+        #[ink::test]
+        fn burning_batch_tokens_works() {
+            let mut erc = Contract::new();
+
+            set_sender(alice());
+            assert!(erc.create(10, 1u128).is_ok());
+            assert!(erc.create(20, 2u128).is_ok());
+
+            assert!(erc.burn_batch(alice(), vec![1, 2], vec![4, 5]).is_ok());
+            assert_eq!(erc.balance_of_batch(vec![alice()], vec![1, 2]), vec![6, 15]);
+            assert_eq!(erc.total_supply_all(), 21);
+        }
+
+        #[ink::test]
+        fn setting_per_token_uri_works() {
+            let mut erc = Contract::new();
+
+            set_sender(alice());
+            assert!(erc.create(10, 1u128).is_ok());
+            assert_eq!(erc.uri(1), None);
+
+            assert!(erc.set_uri(1, String::from("ipfs://song-1")).is_ok());
+            assert_eq!(erc.uri(1), Some(String::from("ipfs://song-1")));
+        }
+
+        #[ink::test]
+        fn setting_uri_requires_ownership() {
+            let mut erc = Contract::new();
+
+            set_sender(alice());
+            assert!(erc.create(10, 1u128).is_ok());
+
+            set_sender(bob());
+            let res = erc.set_uri(1, String::from("ipfs://song-1"));
+            assert_eq!(res.unwrap_err(), Error::UnexistentTokenOrCallerNotOwner);
+        }
+
+        #[ink::test]
+        fn holding_a_balance_does_not_grant_uri_rights() {
+            let mut erc = Contract::new();
+
+            set_sender(alice());
+            assert!(erc.create(10, 1u128).is_ok());
+            assert!(erc.safe_transfer_from(alice(), bob(), 1, 10, vec![]).is_ok());
+
+            // Bob now holds the entire balance but is not the token's creator.
+            set_sender(bob());
+            let res = erc.set_uri(1, String::from("ipfs://song-1"));
+            assert_eq!(res.unwrap_err(), Error::UnexistentTokenOrCallerNotOwner);
+
+            // Alice, the creator, retains the right to update the URI despite holding
+            // no balance.
+            set_sender(alice());
+            assert!(erc.set_uri(1, String::from("ipfs://song-1")).is_ok());
+        }
+
+        #[ink::test]
+        fn base_uri_substitutes_token_id() {
+            let erc = Contract::new_with_base_uri(String::from("https://songs.example/{id}.json"));
+
+            assert_eq!(
+                erc.uri(1),
+                Some(String::from(
+                    "https://songs.example/0000000000000000000000000000000000000000000000000000000000000001.json"
+                ))
+            );
+        }
+
+        #[ink::test]
+        fn voucher_replay_is_rejected() {
+            let mut erc = Contract::new();
+            let voucher = Voucher {
+                token_id: 1,
+                value: 5,
+                recipient: alice(),
+                nonce: 7,
+            };
+            erc.used_nonces.insert(voucher.nonce, &());
+
+            let res = erc.mint_with_voucher(voucher, [0u8; 65]);
+            assert_eq!(res.unwrap_err(), Error::VoucherAlreadyUsed);
+        }
+
+        #[ink::test]
+        fn voucher_with_invalid_signature_is_rejected() {
+            let mut erc = Contract::new();
+            let voucher = Voucher {
+                token_id: 1,
+                value: 5,
+                recipient: alice(),
+                nonce: 1,
+            };
+
+            let res = erc.mint_with_voucher(voucher, [0u8; 65]);
+            assert_eq!(res.unwrap_err(), Error::InvalidVoucherSignature);
+        }
+
+        #[ink::test]
+        fn minting_not_allowed_for_non_creator() {
+            let mut erc = Contract::new();
+
+            set_sender(alice());
+            assert!(erc.create(10, 1u128).is_ok());
+
+            set_sender(bob());
+            let res = erc.mint(1, 5);
+            assert_eq!(res.unwrap_err(), Error::UnexistentTokenOrCallerNotOwner);
+        }
     }
 }